@@ -1,42 +1,82 @@
 pub use glfw::InitError;
-pub use luminance::state::StateQueryError;
 
 use std::error::Error;
 use std::fmt;
 
-/// Error that can be risen while creating a surface.
-#[derive(Debug)]
-pub enum GlfwSurfaceError {
-  InitError(InitError),
+/// Error that can be risen while creating a [`GLFWDevice`](crate::GLFWDevice).
+///
+/// This is the single error type for the crate: every window- and context-creation failure,
+/// whatever constructor it comes from (`Device::new`, [`GLFWDevice::with_gl_hints`],
+/// [`GLFWDevice::new_headless`], [`GLFWDeviceBuilder::build`], …), resolves to one of these
+/// variants instead of an opaque string. Variants wrapping an underlying error expose it through
+/// [`Error::source`].
+///
+/// [`GLFWDevice::with_gl_hints`]: crate::GLFWDevice::with_gl_hints
+/// [`GLFWDevice::new_headless`]: crate::GLFWDevice::new_headless
+/// [`GLFWDeviceBuilder::build`]: crate::GLFWDeviceBuilder::build
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum GLFWDeviceError {
+  /// GLFW itself failed to initialize.
+  Init(InitError),
+  /// The requested context version or profile isn't supported by the driver.
+  ///
+  /// Recovered from GLFW's error callback (`glfw::Error::VersionUnavailable`), which is the
+  /// only way to tell this apart from other `glfwCreateWindow` failures: the call itself just
+  /// returns `NULL` either way.
+  UnsupportedContextVersion,
+  /// No supported pixel format matched the requested framebuffer configuration.
+  ///
+  /// Recovered from GLFW's error callback (`glfw::Error::FormatUnavailable`); see
+  /// [`GLFWDeviceError::UnsupportedContextVersion`] for why this can't be told apart from the
+  /// return value of `glfwCreateWindow` alone.
+  NoSuitableFramebuffer,
+  /// Window or context creation failed for a reason GLFW's error callback didn't report, or no
+  /// callback was able to observe.
   WindowCreationFailed,
+  /// No monitor is connected, or none is reported as primary.
   NoPrimaryMonitor,
+  /// The monitor index passed to a monitor-selecting constructor doesn't exist.
+  NoSuchMonitor,
+  /// The chosen monitor reported no current video mode.
   NoVideoMode,
-  GraphicsStateError(StateQueryError),
+  /// A headless device was asked for a `WindowDim::Fullscreen` window, which has no defined
+  /// size without a monitor to query: headless windows are never shown, so there's no monitor
+  /// to ask for a video mode, and silently picking a size would hide the caller's mistake.
+  AmbiguousHeadlessDimension
 }
 
-impl fmt::Display for GlfwSurfaceError {
-  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-    f.write_str(self.description())
-  }
-}
-
-impl Error for GlfwSurfaceError {
-  fn description(&self) -> &str {
+impl fmt::Display for GLFWDeviceError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
     match *self {
-      GlfwSurfaceError::InitError(_) => "initialization error",
-      GlfwSurfaceError::WindowCreationFailed => "failed to create window",
-      GlfwSurfaceError::NoPrimaryMonitor => "no primary monitor",
-      GlfwSurfaceError::NoVideoMode => "no video mode",
-      GlfwSurfaceError::GraphicsStateError(_) => "failed to get graphics state",
+      GLFWDeviceError::Init(ref e) => write!(f, "failed to initialize GLFW: {}", e),
+      GLFWDeviceError::UnsupportedContextVersion => {
+        f.write_str("the requested OpenGL context version or profile isn't supported")
+      }
+      GLFWDeviceError::NoSuitableFramebuffer => {
+        f.write_str("no framebuffer configuration matches the requested pixel format")
+      }
+      GLFWDeviceError::WindowCreationFailed => f.write_str("failed to create window"),
+      GLFWDeviceError::NoPrimaryMonitor => f.write_str("no primary monitor"),
+      GLFWDeviceError::NoSuchMonitor => f.write_str("no monitor at the given index"),
+      GLFWDeviceError::NoVideoMode => f.write_str("no video mode"),
+      GLFWDeviceError::AmbiguousHeadlessDimension => {
+        f.write_str("headless devices require an explicit size; WindowDim::Fullscreen has none")
+      }
     }
   }
+}
 
-  fn cause(&self) -> Option<&Error> {
+impl Error for GLFWDeviceError {
+  fn source(&self) -> Option<&(Error + 'static)> {
     match *self {
-      GlfwSurfaceError::InitError(ref e) => Some(e),
-      GlfwSurfaceError::GraphicsStateError(ref e) => Some(e),
+      GLFWDeviceError::Init(ref e) => Some(e),
       _ => None
     }
   }
 }
 
+impl From<InitError> for GLFWDeviceError {
+  fn from(e: InitError) -> Self {
+    GLFWDeviceError::Init(e)
+  }
+}
@@ -2,43 +2,90 @@ extern crate gl;
 extern crate glfw;
 extern crate luminance_windowing;
 
-use glfw::{Context, CursorMode, SwapInterval, Window, WindowMode};
+mod error;
+
+use glfw::{Callback, Context, CursorMode, SwapInterval, Window, WindowMode};
 pub use glfw::{Action, InitError, Key, MouseButton, WindowEvent};
 pub use luminance_windowing::{Device, WindowDim, WindowOpt};
+use std::cell::Cell;
 use std::os::raw::c_void;
-use std::error::Error;
-use std::fmt;
+use std::rc::Rc;
 use std::sync::mpsc::Receiver;
 
-/// Error that can be risen while creating a `Device` object.
+pub use error::GLFWDeviceError;
+
+/// Information about a monitor connected to the system.
+///
+/// Returned by [`GLFWDevice::monitors`]; the [`index`](MonitorInfo::index) field can be fed back
+/// into [`GLFWDevice::new_fullscreen_on_monitor`] to pick that monitor explicitly.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonitorInfo {
+  /// Index of the monitor, in the order GLFW reports connected monitors.
+  pub index: usize,
+  /// Human-readable name of the monitor.
+  pub name: String,
+  /// Physical size of the monitor, in millimeters.
+  pub physical_size: (u32, u32),
+  /// Width, in pixels, of the monitor's current video mode.
+  pub width: u32,
+  /// Height, in pixels, of the monitor's current video mode.
+  pub height: u32,
+  /// Refresh rate, in Hz, of the monitor's current video mode.
+  pub refresh_rate: u32
+}
+
+/// OpenGL profile requested when creating the context.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub enum GLFWDeviceError {
-  InitError(InitError),
-  WindowCreationFailed,
-  NoPrimaryMonitor,
-  NoVideoMode
+pub enum GLProfile {
+  /// Core profile: no deprecated, fixed-pipeline functionality.
+  Core,
+  /// Compatibility profile: keeps deprecated functionality around.
+  Compatibility
 }
 
-impl fmt::Display for GLFWDeviceError {
-  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-    f.write_str(self.description())
-  }
+/// Vertical synchronization behavior, mapped onto `glfw::SwapInterval` when creating a device.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VSync {
+  /// Never wait for a vertical blank; swap as fast as possible.
+  Disabled,
+  /// Wait for `n` vertical blanks between each buffer swap.
+  Enabled(u32),
+  /// Like `Enabled(1)`, but swap immediately instead of waiting if a frame is already late.
+  Adaptive
 }
 
-impl Error for GLFWDeviceError {
-  fn description(&self) -> &str {
-    match *self {
-      GLFWDeviceError::InitError(_) => "initialization error",
-      GLFWDeviceError::WindowCreationFailed => "failed to create window",
-      GLFWDeviceError::NoPrimaryMonitor => "no primary monitor",
-      GLFWDeviceError::NoVideoMode => "no video mode"
+impl VSync {
+  fn into_swap_interval(self) -> SwapInterval {
+    match self {
+      VSync::Disabled => SwapInterval::None,
+      VSync::Enabled(n) => SwapInterval::Sync(n),
+      VSync::Adaptive => SwapInterval::Adaptive
     }
   }
+}
+
+/// Requested OpenGL context: version, profile and vsync behavior.
+///
+/// Defaults to the context this crate has always created (GL 3.3 Core, vsync on).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct GLHints {
+  /// Requested context major version.
+  pub major: u32,
+  /// Requested context minor version.
+  pub minor: u32,
+  /// Requested context profile.
+  pub profile: GLProfile,
+  /// Requested vsync behavior.
+  pub vsync: VSync
+}
 
-  fn cause(&self) -> Option<&Error> {
-    match *self {
-      GLFWDeviceError::InitError(ref e) => Some(e),
-      _ => None
+impl Default for GLHints {
+  fn default() -> Self {
+    GLHints {
+      major: 3,
+      minor: 3,
+      profile: GLProfile::Core,
+      vsync: VSync::Enabled(1)
     }
   }
 }
@@ -50,7 +97,468 @@ pub struct GLFWDevice {
   /// Window.
   window: Window,
   /// Window events queue.
-  events: Receiver<(f64, WindowEvent)>
+  events: Receiver<(f64, WindowEvent)>,
+  /// Whether the window is an invisible, off-screen one.
+  ///
+  /// Headless devices never swap buffers, as there is no visible surface to present to.
+  headless: bool
+}
+
+/// Apply the OpenGL context hints to an initialized GLFW instance, for the windows it creates next.
+fn set_gl_hints(glfw: &mut glfw::Glfw, hints: GLHints) {
+  let profile = match hints.profile {
+    GLProfile::Core => glfw::OpenGlProfileHint::Core,
+    GLProfile::Compatibility => glfw::OpenGlProfileHint::Compat
+  };
+
+  glfw.window_hint(glfw::WindowHint::OpenGlProfile(profile));
+
+  // forward-compatible contexts are only a valid combination with the core profile: paired with
+  // the compatibility profile, drivers reject context creation outright
+  if hints.profile == GLProfile::Core {
+    glfw.window_hint(glfw::WindowHint::OpenGlForwardCompat(true));
+  }
+
+  glfw.window_hint(glfw::WindowHint::ContextVersionMajor(hints.major));
+  glfw.window_hint(glfw::WindowHint::ContextVersionMinor(hints.minor));
+}
+
+/// The last `glfw::Error` observed through the error callback installed by [`init_glfw`] or
+/// [`track_glfw_errors`], if any.
+///
+/// `glfwCreateWindow` itself only ever returns `NULL` on failure, with no way to recover the
+/// specific cause from the call site; this is populated by GLFW's error callback instead, which
+/// fires with the actual cause right before the failing call returns.
+type LastGlfwError = Rc<Cell<Option<glfw::Error>>>;
+
+fn record_glfw_error(error: glfw::Error, _description: String, last_error: &LastGlfwError) {
+  last_error.set(Some(error));
+}
+
+/// Build an error callback that records the last error observed into `last_error`, in place of
+/// `glfw::FAIL_ON_ERRORS`'s default behavior of panicking.
+fn error_callback(
+  last_error: LastGlfwError
+) -> glfw::Callback<fn(glfw::Error, String, &LastGlfwError), LastGlfwError> {
+  Callback {
+    f: record_glfw_error,
+    data: last_error
+  }
+}
+
+/// Initialize GLFW with an error callback that captures the last observed `glfw::Error` instead
+/// of panicking, so window- and context-creation failures can be mapped onto a specific
+/// [`GLFWDeviceError`] variant instead of the opaque [`GLFWDeviceError::WindowCreationFailed`].
+fn init_glfw() -> Result<(glfw::Glfw, LastGlfwError), GLFWDeviceError> {
+  let last_error: LastGlfwError = Rc::new(Cell::new(None));
+  let glfw = glfw::init(Some(error_callback(last_error.clone()))).map_err(GLFWDeviceError::Init)?;
+
+  Ok((glfw, last_error))
+}
+
+/// Start capturing `glfw`'s errors into a fresh [`LastGlfwError`], overwriting whatever error
+/// callback it had before.
+///
+/// Used instead of [`init_glfw`] when reusing an already-initialized `glfw::Glfw` instance (see
+/// [`GLFWDevice::new_window`]), since `glfw::init` can only be called once.
+fn track_glfw_errors(glfw: &mut glfw::Glfw) -> LastGlfwError {
+  let last_error: LastGlfwError = Rc::new(Cell::new(None));
+  glfw.set_error_callback(Some(error_callback(last_error.clone())));
+  last_error
+}
+
+/// Map the last observed GLFW error, if any, onto the [`GLFWDeviceError`] variant it indicates,
+/// falling back to the opaque [`GLFWDeviceError::WindowCreationFailed`] when none was captured or
+/// it doesn't correspond to a more specific variant.
+fn window_creation_error(last_error: &LastGlfwError) -> GLFWDeviceError {
+  match last_error.take() {
+    Some(glfw::Error::VersionUnavailable) => GLFWDeviceError::UnsupportedContextVersion,
+    Some(glfw::Error::FormatUnavailable) => GLFWDeviceError::NoSuitableFramebuffer,
+    _ => GLFWDeviceError::WindowCreationFailed
+  }
+}
+
+/// Open a window, sharing its OpenGL context with `share` when given instead of creating an
+/// independent one.
+///
+/// GLFW only shares a context when told to explicitly at creation time
+/// (`glfwCreateWindow`'s `share` parameter, exposed here as `Window::create_shared`); it is
+/// never implicit between windows opened from the same `glfw::Glfw` instance.
+fn open_window(
+  glfw: &mut glfw::Glfw,
+  w: u32,
+  h: u32,
+  title: &str,
+  mode: WindowMode,
+  share: Option<&GLFWDevice>
+) -> Option<(Window, Receiver<(f64, WindowEvent)>)> {
+  match share {
+    Some(device) => device.window.create_shared(w, h, title, mode),
+    None => glfw.create_window(w, h, title, mode)
+  }
+}
+
+/// Create a window for the given `dim`, picking the monitor at `monitor_index` for fullscreen
+/// modes instead of always the primary one when it's given, and sharing its OpenGL context
+/// with `share` when given (see [`GLFWDevice::new_window`]).
+///
+/// Shared by every constructor that opens a visible window, so picking a specific monitor
+/// ([`GLFWDevice::new_fullscreen_on_monitor`]) and configuring GL hints ([`GLFWDevice::with_gl_hints`])
+/// aren't each a one-off reimplementation of this match.
+///
+/// `last_error` is consulted to attribute a `NULL` return from the underlying `glfwCreateWindow`
+/// call to a specific cause (see [`window_creation_error`]); callers get it from [`init_glfw`] or
+/// [`track_glfw_errors`].
+fn create_window(
+  glfw: &mut glfw::Glfw,
+  dim: WindowDim,
+  monitor_index: Option<usize>,
+  title: &str,
+  share: Option<&GLFWDevice>,
+  last_error: &LastGlfwError
+) -> Result<(Window, Receiver<(f64, WindowEvent)>), GLFWDeviceError> {
+  match (dim, monitor_index) {
+    (WindowDim::Windowed(w, h), _) => {
+      open_window(glfw, w, h, title, WindowMode::Windowed, share).ok_or_else(|| window_creation_error(last_error))
+    },
+    (WindowDim::Fullscreen, Some(monitor_index)) => {
+      glfw.with_connected_monitors(|glfw, monitors| {
+        let monitor = monitors.get(monitor_index).ok_or(GLFWDeviceError::NoSuchMonitor)?;
+        let vmode = monitor.get_video_mode().ok_or(GLFWDeviceError::NoVideoMode)?;
+
+        open_window(glfw, vmode.width, vmode.height, title, WindowMode::FullScreen(monitor), share)
+            .ok_or_else(|| window_creation_error(last_error))
+      })
+    },
+    (WindowDim::Fullscreen, None) => {
+      glfw.with_primary_monitor(|glfw, monitor| {
+        let monitor = monitor.ok_or(GLFWDeviceError::NoPrimaryMonitor)?;
+        let vmode = monitor.get_video_mode().ok_or(GLFWDeviceError::NoVideoMode)?;
+
+        open_window(glfw, vmode.width, vmode.height, title, WindowMode::FullScreen(monitor), share)
+            .ok_or_else(|| window_creation_error(last_error))
+      })
+    },
+    (WindowDim::FullscreenRestricted(w, h), Some(monitor_index)) => {
+      glfw.with_connected_monitors(|glfw, monitors| {
+        let monitor = monitors.get(monitor_index).ok_or(GLFWDeviceError::NoSuchMonitor)?;
+
+        open_window(glfw, w, h, title, WindowMode::FullScreen(monitor), share)
+            .ok_or_else(|| window_creation_error(last_error))
+      })
+    },
+    (WindowDim::FullscreenRestricted(w, h), None) => {
+      glfw.with_primary_monitor(|glfw, monitor| {
+        let monitor = monitor.ok_or(GLFWDeviceError::NoPrimaryMonitor)?;
+
+        open_window(glfw, w, h, title, WindowMode::FullScreen(monitor), share)
+            .ok_or_else(|| window_creation_error(last_error))
+      })
+    }
+  }
+}
+
+/// Resolve the fixed `(width, height)` a headless window is created with.
+///
+/// Headless windows are never shown, so there's no monitor to query a video mode from:
+/// `WindowDim::Fullscreen` has no size to fall back to and is rejected outright, rather than
+/// silently substituting an arbitrary one.
+fn headless_dim(dim: WindowDim) -> Result<(u32, u32), GLFWDeviceError> {
+  match dim {
+    WindowDim::Windowed(w, h) | WindowDim::FullscreenRestricted(w, h) => Ok((w, h)),
+    WindowDim::Fullscreen => Err(GLFWDeviceError::AmbiguousHeadlessDimension)
+  }
+}
+
+/// Builder for [`GLFWDevice`].
+///
+/// Collects window and context configuration through chained setters, then creates the device
+/// with a terminal call to [`GLFWDeviceBuilder::build`]. New window attributes can grow as
+/// builder methods without ever breaking existing callers, unlike the fixed [`Device::new`]
+/// signature.
+#[derive(Clone, Debug)]
+pub struct GLFWDeviceBuilder {
+  title: String,
+  dim: WindowDim,
+  hide_cursor: bool,
+  resizable: bool,
+  decorated: bool,
+  position: Option<(i32, i32)>,
+  monitor_index: Option<usize>,
+  headless: bool,
+  gl_hints: GLHints
+}
+
+impl Default for GLFWDeviceBuilder {
+  fn default() -> Self {
+    GLFWDeviceBuilder {
+      title: String::new(),
+      dim: WindowDim::Windowed(960, 540),
+      hide_cursor: false,
+      resizable: true,
+      decorated: true,
+      position: None,
+      monitor_index: None,
+      headless: false,
+      gl_hints: GLHints::default()
+    }
+  }
+}
+
+impl GLFWDeviceBuilder {
+  /// Create a new builder with the crate's default window and context configuration.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Set the window title.
+  pub fn title<S>(mut self, title: S) -> Self where S: Into<String> {
+    self.title = title.into();
+    self
+  }
+
+  /// Set the window dimension (windowed, fullscreen or fullscreen-restricted).
+  ///
+  /// Combined with [`GLFWDeviceBuilder::headless`], only `Windowed` and `FullscreenRestricted`
+  /// are accepted: a headless window is never shown, so there's no monitor to size a
+  /// `Fullscreen` one from (see [`GLFWDeviceError::AmbiguousHeadlessDimension`]).
+  pub fn dim(mut self, dim: WindowDim) -> Self {
+    self.dim = dim;
+    self
+  }
+
+  /// Hide the cursor once the window is created.
+  pub fn hide_cursor(mut self, hide_cursor: bool) -> Self {
+    self.hide_cursor = hide_cursor;
+    self
+  }
+
+  /// Allow the user to resize the window.
+  pub fn resizable(mut self, resizable: bool) -> Self {
+    self.resizable = resizable;
+    self
+  }
+
+  /// Draw the window's border and title bar.
+  pub fn decorated(mut self, decorated: bool) -> Self {
+    self.decorated = decorated;
+    self
+  }
+
+  /// Set the window's initial position, in screen coordinates.
+  pub fn position(mut self, x: i32, y: i32) -> Self {
+    self.position = Some((x, y));
+    self
+  }
+
+  /// Open a fullscreen window on a specific monitor (see [`GLFWDevice::monitors`]) instead of
+  /// the primary one. Has no effect unless `dim` is `Fullscreen` or `FullscreenRestricted`.
+  pub fn monitor(mut self, monitor_index: usize) -> Self {
+    self.monitor_index = Some(monitor_index);
+    self
+  }
+
+  /// Create an invisible, off-screen window instead of a visible one.
+  ///
+  /// See [`GLFWDeviceBuilder::dim`] for the restriction this places on the window dimension.
+  pub fn headless(mut self, headless: bool) -> Self {
+    self.headless = headless;
+    self
+  }
+
+  /// Set the requested OpenGL context version, profile and vsync behavior.
+  pub fn gl_hints(mut self, gl_hints: GLHints) -> Self {
+    self.gl_hints = gl_hints;
+    self
+  }
+
+  /// Create the device with the collected configuration.
+  pub fn build(self) -> Result<GLFWDevice, GLFWDeviceError> {
+    let (mut glfw, last_error) = init_glfw()?;
+
+    set_gl_hints(&mut glfw, self.gl_hints);
+    glfw.window_hint(glfw::WindowHint::Resizable(self.resizable));
+    glfw.window_hint(glfw::WindowHint::Decorated(self.decorated));
+
+    let (mut window, events) = if self.headless {
+      glfw.window_hint(glfw::WindowHint::Visible(false));
+
+      let (w, h) = headless_dim(self.dim)?;
+
+      glfw.create_window(w, h, &self.title, WindowMode::Windowed)
+          .ok_or_else(|| window_creation_error(&last_error))?
+    } else {
+      create_window(&mut glfw, self.dim, self.monitor_index, &self.title, None, &last_error)?
+    };
+
+    window.make_current();
+
+    if let Some((x, y)) = self.position {
+      window.set_pos(x, y);
+    }
+
+    if self.hide_cursor {
+      window.set_cursor_mode(CursorMode::Disabled);
+    }
+
+    window.set_all_polling(true);
+    glfw.set_swap_interval(self.gl_hints.vsync.into_swap_interval());
+
+    // init OpenGL
+    gl::load_with(|s| window.get_proc_address(s) as *const c_void);
+
+    Ok(GLFWDevice {
+      window: window,
+      events: events,
+      headless: self.headless
+    })
+  }
+}
+
+impl GLFWDevice {
+  /// Create a new `GLFWDevice`, just like [`Device::new`], but with explicit control over the
+  /// OpenGL context version, profile and vsync behavior instead of the fixed GL 3.3 Core,
+  /// vsync-on defaults.
+  pub fn with_gl_hints(dim: WindowDim, title: &str, win_opt: WindowOpt, hints: GLHints) -> Result<Self, GLFWDeviceError> {
+    let (mut glfw, last_error) = init_glfw()?;
+
+    set_gl_hints(&mut glfw, hints);
+
+    let (mut window, events) = create_window(&mut glfw, dim, None, title, None, &last_error)?;
+
+    window.make_current();
+
+    if win_opt.is_cursor_hidden() {
+      window.set_cursor_mode(CursorMode::Disabled);
+    }
+
+    window.set_all_polling(true);
+    glfw.set_swap_interval(hints.vsync.into_swap_interval());
+
+    // init OpenGL
+    gl::load_with(|s| window.get_proc_address(s) as *const c_void);
+
+    Ok(GLFWDevice {
+      window: window,
+      events: events,
+      headless: false
+    })
+  }
+
+  /// Create a new, invisible `GLFWDevice` suited for off-screen rendering.
+  ///
+  /// This opens a GLFW window the same way [`Device::new`] does, except the window is never
+  /// shown (`glfw::WindowHint::Visible(false)`). This is handy to exercise luminance pipelines
+  /// on headless CI runners, which usually have no display server to present to.
+  pub fn new_headless(width: u32, height: u32, title: &str, win_opt: WindowOpt) -> Result<Self, GLFWDeviceError> {
+    let (mut glfw, last_error) = init_glfw()?;
+
+    set_gl_hints(&mut glfw, GLHints::default());
+    glfw.window_hint(glfw::WindowHint::Visible(false));
+
+    let (mut window, events) =
+      glfw.create_window(width, height, title, WindowMode::Windowed)
+          .ok_or_else(|| window_creation_error(&last_error))?;
+
+    window.make_current();
+
+    if win_opt.is_cursor_hidden() {
+      window.set_cursor_mode(CursorMode::Disabled);
+    }
+
+    window.set_all_polling(true);
+    glfw.set_swap_interval(GLHints::default().vsync.into_swap_interval());
+
+    // init OpenGL
+    gl::load_with(|s| window.get_proc_address(s) as *const c_void);
+
+    Ok(GLFWDevice {
+      window: window,
+      events: events,
+      headless: true
+    })
+  }
+
+  /// List the monitors currently connected to the system, in the order GLFW reports them.
+  pub fn monitors() -> Result<Vec<MonitorInfo>, GLFWDeviceError> {
+    let (mut glfw, _last_error) = init_glfw()?;
+
+    Ok(glfw.with_connected_monitors(|_, monitors| {
+      monitors.iter().enumerate().filter_map(|(index, monitor)| {
+        let vmode = monitor.get_video_mode()?;
+        let (width_mm, height_mm) = monitor.get_physical_size();
+
+        Some(MonitorInfo {
+          index: index,
+          name: monitor.get_name().unwrap_or_default(),
+          physical_size: (width_mm as u32, height_mm as u32),
+          width: vmode.width,
+          height: vmode.height,
+          refresh_rate: vmode.refresh_rate
+        })
+      }).collect()
+    }))
+  }
+
+  /// Open a fullscreen window on the monitor at `monitor_index` (see [`GLFWDevice::monitors`]),
+  /// instead of always the primary monitor.
+  pub fn new_fullscreen_on_monitor(monitor_index: usize, title: &str, win_opt: WindowOpt) -> Result<Self, GLFWDeviceError> {
+    GLFWDeviceBuilder::new()
+      .title(title)
+      .dim(WindowDim::Fullscreen)
+      .monitor(monitor_index)
+      .hide_cursor(win_opt.is_cursor_hidden())
+      .build()
+  }
+
+  /// Open an additional window on an already-initialized `glfw::Glfw` instance instead of calling
+  /// `glfw::init` again.
+  ///
+  /// This is how several windows are driven from a single backend: create a first `GLFWDevice`
+  /// (with [`Device::new`] or any other constructor), grab its GLFW handle with
+  /// [`GLFWDevice::glfw`], and pass it here to open further windows on the same event loop.
+  ///
+  /// By default the new window gets its own independent OpenGL context: GLFW never shares
+  /// context state (textures, buffers, …) between windows unless told to do so explicitly.
+  /// Pass an existing `GLFWDevice` as `share` to create the new window's context shared with
+  /// that device's instead.
+  pub fn new_window(
+    glfw: &mut glfw::Glfw,
+    dim: WindowDim,
+    title: &str,
+    win_opt: WindowOpt,
+    share: Option<&GLFWDevice>
+  ) -> Result<Self, GLFWDeviceError> {
+    let last_error = track_glfw_errors(glfw);
+
+    set_gl_hints(glfw, GLHints::default());
+
+    let (mut window, events) = create_window(glfw, dim, None, title, share, &last_error)?;
+
+    window.make_current();
+
+    if win_opt.is_cursor_hidden() {
+      window.set_cursor_mode(CursorMode::Disabled);
+    }
+
+    window.set_all_polling(true);
+    glfw.set_swap_interval(GLHints::default().vsync.into_swap_interval());
+
+    // init OpenGL
+    gl::load_with(|s| window.get_proc_address(s) as *const c_void);
+
+    Ok(GLFWDevice {
+      window: window,
+      events: events,
+      headless: false
+    })
+  }
+
+  /// Access this device's underlying `glfw::Glfw` instance, e.g. to open more windows on it with
+  /// [`GLFWDevice::new_window`].
+  pub fn glfw(&mut self) -> &mut glfw::Glfw {
+    &mut self.window.glfw
+  }
 }
 
 impl Device for GLFWDevice {
@@ -58,49 +566,11 @@ impl Device for GLFWDevice {
   type Error = GLFWDeviceError;
 
   fn new(dim: WindowDim, title: &str, win_opt: WindowOpt) -> Result<Self, Self::Error> {
-    let mut glfw = glfw::init(glfw::FAIL_ON_ERRORS).map_err(GLFWDeviceError::InitError)?;
+    let (mut glfw, last_error) = init_glfw()?;
 
-    // OpenGL hints
-    glfw.window_hint(glfw::WindowHint::OpenGlProfile(glfw::OpenGlProfileHint::Core));
-    glfw.window_hint(glfw::WindowHint::OpenGlForwardCompat(true));
-    glfw.window_hint(glfw::WindowHint::ContextVersionMajor(3));
-    glfw.window_hint(glfw::WindowHint::ContextVersionMinor(3));
-
-    // open a window in windowed or fullscreen mode
-    let (mut window, events) = match dim {
-      WindowDim::Windowed(w, h) => {
-        glfw.create_window(w,
-                           h,
-                           title,
-                           WindowMode::Windowed).ok_or(GLFWDeviceError::WindowCreationFailed)?
-      },
-      WindowDim::Fullscreen => {
-        glfw.with_primary_monitor(|glfw, monitor| {
-          let monitor = monitor.ok_or(GLFWDeviceError::NoPrimaryMonitor)?;
-          let vmode = monitor.get_video_mode().ok_or(GLFWDeviceError::NoVideoMode)?;
-          let (w, h) = (vmode.width, vmode.height);
-
-          Ok(glfw.create_window(
-              w,
-              h,
-              title,
-              WindowMode::FullScreen(monitor)
-              ).ok_or(GLFWDeviceError::WindowCreationFailed)?)
-        })?
-      },
-      WindowDim::FullscreenRestricted(w, h) => {
-        glfw.with_primary_monitor(|glfw, monitor| {
-          let monitor = monitor.ok_or(GLFWDeviceError::NoPrimaryMonitor)?;
-
-          Ok(glfw.create_window(
-              w,
-              h,
-              title,
-              WindowMode::FullScreen(monitor)
-              ).ok_or(GLFWDeviceError::WindowCreationFailed)?)
-        })?
-      }
-    };
+    set_gl_hints(&mut glfw, GLHints::default());
+
+    let (mut window, events) = create_window(&mut glfw, dim, None, title, None, &last_error)?;
 
     window.make_current();
 
@@ -109,14 +579,15 @@ impl Device for GLFWDevice {
     }
 
     window.set_all_polling(true);
-    glfw.set_swap_interval(SwapInterval::Sync(1));
+    glfw.set_swap_interval(GLHints::default().vsync.into_swap_interval());
 
     // init OpenGL
     gl::load_with(|s| window.get_proc_address(s) as *const c_void);
 
     Ok(GLFWDevice {
       window: window,
-      events: events
+      events: events,
+      headless: false
     })
   }
 
@@ -131,7 +602,16 @@ impl Device for GLFWDevice {
   }
 
   fn draw<F>(&mut self, f: F) where F: FnOnce() {
+    // make sure this window's context is the current one: when juggling several windows, the
+    // last-made-current one isn't necessarily this one
+    self.window.make_current();
+
     f();
-    self.window.swap_buffers();
+
+    // headless devices have nothing to present to; swapping would be a no-op at best and a
+    // driver error at worst on some platforms
+    if !self.headless {
+      self.window.swap_buffers();
+    }
   }
 }